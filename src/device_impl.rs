@@ -0,0 +1,679 @@
+use embedded_hal::blocking::delay::DelayMs;
+use embedded_hal::blocking::i2c::{Write, WriteRead};
+
+use crate::{
+    mode, AutomaticMeasurementMode, Error, Hdc20xx, InterruptMask, InterruptPolarity, Measurement,
+    Resolution, Status,
+};
+
+struct Register;
+impl Register {
+    const TEMPERATURE_LOW: u8 = 0x00;
+    const TEMPERATURE_HIGH: u8 = 0x01;
+    const HUMIDITY_LOW: u8 = 0x02;
+    const HUMIDITY_HIGH: u8 = 0x03;
+    const INTERRUPT_DRDY: u8 = 0x04;
+    const INTERRUPT_ENABLE: u8 = 0x07;
+    const TEMP_OFFSET_ADJUST: u8 = 0x08;
+    const HUMIDITY_OFFSET_ADJUST: u8 = 0x09;
+    const TEMP_THR_LOW: u8 = 0x0A;
+    const TEMP_THR_HIGH: u8 = 0x0B;
+    const HUMIDITY_THR_LOW: u8 = 0x0C;
+    const HUMIDITY_THR_HIGH: u8 = 0x0D;
+    const RESET_DRDY_INT_CONF: u8 = 0x0E;
+    const MEASUREMENT_CONF: u8 = 0x0F;
+    const MANUFACTURER_ID_LOW: u8 = 0xFC;
+    const MANUFACTURER_ID_HIGH: u8 = 0xFD;
+    const DEVICE_ID_LOW: u8 = 0xFE;
+    const DEVICE_ID_HIGH: u8 = 0xFF;
+}
+
+/// Manufacturer ID of Texas Instruments, common to the whole HDC20xx family.
+const TI_MANUFACTURER_ID: u16 = 0x5449;
+
+/// Time the device needs to boot after a software reset, per the datasheet.
+const SOFT_RESET_DELAY_MS: u8 = 2;
+
+struct BitFlags;
+impl BitFlags {
+    const DRDY: u8 = 0b1000_0000;
+    const TH_EXCEEDED: u8 = 0b0100_0000;
+    const TL_EXCEEDED: u8 = 0b0010_0000;
+    const HH_EXCEEDED: u8 = 0b0001_0000;
+    const HL_EXCEEDED: u8 = 0b0000_1000;
+    const SOFT_RES: u8 = 0b1000_0000;
+    const HEAT_EN: u8 = 0b0000_1000;
+    const DRDY_INT_EN: u8 = 0b0000_0100;
+    const INT_POL: u8 = 0b0000_0010;
+    const MEAS_TRIG: u8 = 0b0000_0010;
+    const AMM_MASK: u8 = 0b0111_0000;
+    const TRES_MASK: u8 = 0b1100_0000;
+    const HRES_MASK: u8 = 0b0011_0000;
+}
+
+/// °C per LSB of the temperature offset-adjustment register.
+const TEMPERATURE_OFFSET_LSB: f32 = 165.0 / 256.0;
+/// %RH per LSB of the humidity offset-adjustment register.
+const HUMIDITY_OFFSET_LSB: f32 = 100.0 / 256.0;
+
+fn resolution_bits(resolution: Resolution) -> u8 {
+    match resolution {
+        Resolution::Bits14 => 0b00,
+        Resolution::Bits11 => 0b01,
+        Resolution::Bits9 => 0b10,
+    }
+}
+
+impl<I2C, E, MODE> Hdc20xx<I2C, MODE>
+where
+    I2C: Write<Error = E> + WriteRead<Error = E>,
+{
+    pub(crate) fn write_register(&mut self, register: u8, value: u8) -> Result<(), Error<E>> {
+        self.i2c
+            .write(self.address, &[register, value])
+            .map_err(Error::I2C)
+    }
+
+    pub(crate) fn read_register(&mut self, register: u8) -> Result<u8, Error<E>> {
+        let mut data = [0];
+        self.i2c
+            .write_read(self.address, &[register], &mut data)
+            .map_err(Error::I2C)?;
+        Ok(data[0])
+    }
+
+    fn set_automatic_measurement_mode(
+        &mut self,
+        rate: AutomaticMeasurementMode,
+    ) -> Result<(), Error<E>> {
+        let current = self.read_register(Register::RESET_DRDY_INT_CONF)?;
+        let new = (current & !BitFlags::AMM_MASK) | (rate as u8 & BitFlags::AMM_MASK);
+        self.write_register(Register::RESET_DRDY_INT_CONF, new)
+    }
+
+    /// Read the data-ready/threshold flags, polled in a loop while waiting
+    /// for a conversion to complete. `heater_active` is filled in separately
+    /// by [`read_measurement()`](Self::read_measurement) once a reading is
+    /// ready, so this doesn't spend a second I2C transaction on every poll.
+    fn read_status(&mut self) -> Result<Status, Error<E>> {
+        let value = self.read_register(Register::INTERRUPT_DRDY)?;
+        Ok(Status {
+            data_ready: (value & BitFlags::DRDY) != 0,
+            high_temp_threshold_exceeded: (value & BitFlags::TH_EXCEEDED) != 0,
+            low_temp_threshold_exceeded: (value & BitFlags::TL_EXCEEDED) != 0,
+            high_humidity_threshold_exceeded: (value & BitFlags::HH_EXCEEDED) != 0,
+            low_humidity_threshold_exceeded: (value & BitFlags::HL_EXCEEDED) != 0,
+            heater_active: false,
+        })
+    }
+
+    /// Enable the on-chip heater to drive off condensation and de-saturate
+    /// the humidity sensor.
+    ///
+    /// While the heater is active, readings are biased by its self-heating.
+    /// [`Status::heater_active`] is set on measurements taken while it is on
+    /// so callers can discard them.
+    pub fn enable_heater(&mut self) -> Result<(), Error<E>> {
+        let current = self.read_register(Register::RESET_DRDY_INT_CONF)?;
+        self.write_register(Register::RESET_DRDY_INT_CONF, current | BitFlags::HEAT_EN)
+    }
+
+    /// Disable the on-chip heater.
+    pub fn disable_heater(&mut self) -> Result<(), Error<E>> {
+        let current = self.read_register(Register::RESET_DRDY_INT_CONF)?;
+        self.write_register(Register::RESET_DRDY_INT_CONF, current & !BitFlags::HEAT_EN)
+    }
+
+    fn read_measurement(&mut self, mut status: Status) -> Result<Measurement, Error<E>> {
+        let conf = self.read_register(Register::RESET_DRDY_INT_CONF)?;
+        status.heater_active = (conf & BitFlags::HEAT_EN) != 0;
+
+        let temp_low = self.read_register(Register::TEMPERATURE_LOW)?;
+        let temp_high = self.read_register(Register::TEMPERATURE_HIGH)?;
+        let raw_temp = u16::from_le_bytes([temp_low, temp_high]);
+        let temperature = (raw_temp as f32 / 65536.0) * 165.0 - 40.0;
+
+        let hum_low = self.read_register(Register::HUMIDITY_LOW)?;
+        let hum_high = self.read_register(Register::HUMIDITY_HIGH)?;
+        let raw_hum = u16::from_le_bytes([hum_low, hum_high]);
+        let humidity = Some((raw_hum as f32 / 65536.0) * 100.0);
+
+        Ok(Measurement {
+            temperature,
+            humidity,
+            status,
+        })
+    }
+
+    /// Read the manufacturer ID.
+    pub fn manufacturer_id(&mut self) -> Result<u16, Error<E>> {
+        let low = self.read_register(Register::MANUFACTURER_ID_LOW)?;
+        let high = self.read_register(Register::MANUFACTURER_ID_HIGH)?;
+        Ok(u16::from_le_bytes([low, high]))
+    }
+
+    /// Read the device ID.
+    pub fn device_id(&mut self) -> Result<u16, Error<E>> {
+        let low = self.read_register(Register::DEVICE_ID_LOW)?;
+        let high = self.read_register(Register::DEVICE_ID_HIGH)?;
+        Ok(u16::from_le_bytes([low, high]))
+    }
+
+    /// Check whether the manufacturer ID matches the expected Texas
+    /// Instruments value, to fail fast on a mis-wired bus.
+    pub fn is_hdc20xx(&mut self) -> Result<bool, Error<E>> {
+        Ok(self.manufacturer_id()? == TI_MANUFACTURER_ID)
+    }
+
+    /// Set the high temperature threshold that raises
+    /// [`Status::high_temp_threshold_exceeded`].
+    ///
+    /// `celsius` must be in the `-40.0..=125.0` range.
+    pub fn set_high_temperature_threshold(&mut self, celsius: f32) -> Result<(), Error<E>> {
+        let msb = encode_temperature(celsius)?;
+        self.write_register(Register::TEMP_THR_HIGH, msb)
+    }
+
+    /// Set the low temperature threshold that raises
+    /// [`Status::low_temp_threshold_exceeded`].
+    ///
+    /// `celsius` must be in the `-40.0..=125.0` range.
+    pub fn set_low_temperature_threshold(&mut self, celsius: f32) -> Result<(), Error<E>> {
+        let msb = encode_temperature(celsius)?;
+        self.write_register(Register::TEMP_THR_LOW, msb)
+    }
+
+    /// Set the high relative humidity threshold that raises
+    /// [`Status::high_humidity_threshold_exceeded`].
+    ///
+    /// `percent_rh` must be in the `0.0..=100.0` range.
+    pub fn set_high_humidity_threshold(&mut self, percent_rh: f32) -> Result<(), Error<E>> {
+        let msb = encode_humidity(percent_rh)?;
+        self.write_register(Register::HUMIDITY_THR_HIGH, msb)
+    }
+
+    /// Set the low relative humidity threshold that raises
+    /// [`Status::low_humidity_threshold_exceeded`].
+    ///
+    /// `percent_rh` must be in the `0.0..=100.0` range.
+    pub fn set_low_humidity_threshold(&mut self, percent_rh: f32) -> Result<(), Error<E>> {
+        let msb = encode_humidity(percent_rh)?;
+        self.write_register(Register::HUMIDITY_THR_LOW, msb)
+    }
+
+    /// Apply a field-calibration offset to the temperature reading.
+    ///
+    /// `celsius` is rounded to the nearest offset-register LSB
+    /// (`165.0 / 256.0` °C).
+    pub fn set_temperature_offset(&mut self, celsius: f32) -> Result<(), Error<E>> {
+        let value = encode_offset(celsius, TEMPERATURE_OFFSET_LSB)?;
+        self.write_register(Register::TEMP_OFFSET_ADJUST, value)
+    }
+
+    /// Apply a field-calibration offset to the humidity reading.
+    ///
+    /// `percent_rh` is rounded to the nearest offset-register LSB
+    /// (`100.0 / 256.0` %RH).
+    pub fn set_humidity_offset(&mut self, percent_rh: f32) -> Result<(), Error<E>> {
+        let value = encode_offset(percent_rh, HUMIDITY_OFFSET_LSB)?;
+        self.write_register(Register::HUMIDITY_OFFSET_ADJUST, value)
+    }
+
+    /// Set the ADC resolution used for temperature conversions.
+    pub fn set_temperature_resolution(&mut self, resolution: Resolution) -> Result<(), Error<E>> {
+        let current = self.read_register(Register::MEASUREMENT_CONF)?;
+        let new = (current & !BitFlags::TRES_MASK) | (resolution_bits(resolution) << 6);
+        self.write_register(Register::MEASUREMENT_CONF, new)
+    }
+
+    /// Set the ADC resolution used for humidity conversions.
+    pub fn set_humidity_resolution(&mut self, resolution: Resolution) -> Result<(), Error<E>> {
+        let current = self.read_register(Register::MEASUREMENT_CONF)?;
+        let new = (current & !BitFlags::HRES_MASK) | (resolution_bits(resolution) << 4);
+        self.write_register(Register::MEASUREMENT_CONF, new)
+    }
+
+    /// Select which status conditions are routed to the INT/DRDY pin.
+    pub fn set_interrupt_mask(&mut self, mask: InterruptMask) -> Result<(), Error<E>> {
+        let mut value = 0;
+        if mask.data_ready {
+            value |= BitFlags::DRDY;
+        }
+        if mask.high_temperature {
+            value |= BitFlags::TH_EXCEEDED;
+        }
+        if mask.low_temperature {
+            value |= BitFlags::TL_EXCEEDED;
+        }
+        if mask.high_humidity {
+            value |= BitFlags::HH_EXCEEDED;
+        }
+        if mask.low_humidity {
+            value |= BitFlags::HL_EXCEEDED;
+        }
+        self.write_register(Register::INTERRUPT_ENABLE, value)
+    }
+
+    /// Set the output polarity of the INT/DRDY pin.
+    pub fn set_interrupt_polarity(&mut self, polarity: InterruptPolarity) -> Result<(), Error<E>> {
+        let current = self.read_register(Register::RESET_DRDY_INT_CONF)?;
+        let new = match polarity {
+            InterruptPolarity::ActiveHigh => current | BitFlags::INT_POL,
+            InterruptPolarity::ActiveLow => current & !BitFlags::INT_POL,
+        };
+        self.write_register(Register::RESET_DRDY_INT_CONF, new)
+    }
+
+    /// Enable the INT/DRDY pin so that the conditions selected with
+    /// [`set_interrupt_mask()`](Hdc20xx::set_interrupt_mask) are signaled on it.
+    pub fn enable_interrupt_pin(&mut self) -> Result<(), Error<E>> {
+        let current = self.read_register(Register::RESET_DRDY_INT_CONF)?;
+        self.write_register(Register::RESET_DRDY_INT_CONF, current | BitFlags::DRDY_INT_EN)
+    }
+
+    /// Disable the INT/DRDY pin.
+    pub fn disable_interrupt_pin(&mut self) -> Result<(), Error<E>> {
+        let current = self.read_register(Register::RESET_DRDY_INT_CONF)?;
+        self.write_register(
+            Register::RESET_DRDY_INT_CONF,
+            current & !BitFlags::DRDY_INT_EN,
+        )
+    }
+}
+
+impl<I2C, E> Hdc20xx<I2C, mode::OneShot>
+where
+    I2C: Write<Error = E> + WriteRead<Error = E>,
+{
+    /// Trigger a measurement and read it once it is ready.
+    pub fn read(&mut self) -> Result<Measurement, Error<E>> {
+        let current = self.read_register(Register::MEASUREMENT_CONF)?;
+        self.write_register(Register::MEASUREMENT_CONF, current | BitFlags::MEAS_TRIG)?;
+
+        let mut status = self.read_status()?;
+        while !status.data_ready {
+            status = self.read_status()?;
+        }
+        self.read_measurement(status)
+    }
+
+    /// Perform a software reset and wait for the device to boot back up.
+    ///
+    /// A software reset clears the automatic measurement configuration, so
+    /// this is only available in one-shot mode; call it before
+    /// [`into_continuous()`](Hdc20xx::into_continuous) rather than after.
+    pub fn software_reset<D: DelayMs<u8>>(&mut self, delay: &mut D) -> Result<(), Error<E>> {
+        let current = self.read_register(Register::RESET_DRDY_INT_CONF)?;
+        self.write_register(Register::RESET_DRDY_INT_CONF, current | BitFlags::SOFT_RES)?;
+        delay.delay_ms(SOFT_RESET_DELAY_MS);
+        Ok(())
+    }
+
+    /// Switch the device into continuous (automatic) measurement mode at the
+    /// given rate, letting it time its own conversions.
+    ///
+    /// On I2C error the original, still one-shot, instance is returned
+    /// alongside the error so the caller can retry or [`destroy()`](Hdc20xx::destroy) it.
+    pub fn into_continuous(
+        mut self,
+        rate: AutomaticMeasurementMode,
+    ) -> Result<Hdc20xx<I2C, mode::Continuous>, (Error<E>, Self)> {
+        if let Err(e) = self.set_automatic_measurement_mode(rate) {
+            return Err((e, self));
+        }
+        Ok(Hdc20xx {
+            i2c: self.i2c,
+            address: self.address,
+            _mode: core::marker::PhantomData,
+        })
+    }
+}
+
+impl<I2C, E> Hdc20xx<I2C, mode::Continuous>
+where
+    I2C: Write<Error = E> + WriteRead<Error = E>,
+{
+    /// Read the latest measurement without triggering a new conversion.
+    ///
+    /// The device converts on its own schedule; this polls `data_ready` and
+    /// returns the latest sample once it is set.
+    pub fn read(&mut self) -> Result<Measurement, Error<E>> {
+        let mut status = self.read_status()?;
+        while !status.data_ready {
+            status = self.read_status()?;
+        }
+        self.read_measurement(status)
+    }
+
+    /// Switch the device back into one-shot measurement mode.
+    ///
+    /// On I2C error the original, still continuous, instance is returned
+    /// alongside the error so the caller can retry or [`destroy()`](Hdc20xx::destroy) it.
+    pub fn into_one_shot(mut self) -> Result<Hdc20xx<I2C, mode::OneShot>, (Error<E>, Self)> {
+        if let Err(e) = self.set_automatic_measurement_mode(AutomaticMeasurementMode::Disabled) {
+            return Err((e, self));
+        }
+        Ok(Hdc20xx {
+            i2c: self.i2c,
+            address: self.address,
+            _mode: core::marker::PhantomData,
+        })
+    }
+}
+
+fn encode_temperature<E>(celsius: f32) -> Result<u8, Error<E>> {
+    if !(-40.0..=125.0).contains(&celsius) {
+        return Err(Error::InvalidInputData);
+    }
+    let raw = ((celsius + 40.0) / 165.0) * 65536.0;
+    let raw = raw.clamp(0.0, 65535.0) as u32;
+    Ok((raw >> 8) as u8)
+}
+
+fn encode_humidity<E>(percent_rh: f32) -> Result<u8, Error<E>> {
+    if !(0.0..=100.0).contains(&percent_rh) {
+        return Err(Error::InvalidInputData);
+    }
+    let raw = (percent_rh / 100.0) * 65536.0;
+    let raw = raw.clamp(0.0, 65535.0) as u32;
+    Ok((raw >> 8) as u8)
+}
+
+fn encode_offset<E>(value: f32, lsb: f32) -> Result<u8, Error<E>> {
+    if !value.is_finite() {
+        return Err(Error::InvalidInputData);
+    }
+    let lsb_count = round(value / lsb);
+    if !(-128.0..=127.0).contains(&lsb_count) {
+        return Err(Error::InvalidInputData);
+    }
+    Ok(lsb_count as i8 as u8)
+}
+
+/// Round to the nearest integer, ties away from zero.
+///
+/// `f32::round()` is unavailable in `#![no_std]` without `libm`, so this
+/// truncates the value shifted by half an LSB instead.
+fn round(value: f32) -> f32 {
+    if value >= 0.0 {
+        (value + 0.5) as i32 as f32
+    } else {
+        (value - 0.5) as i32 as f32
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    extern crate std;
+    use std::vec;
+
+    use crate::{
+        AutomaticMeasurementMode, Hdc20xx, InterruptMask, InterruptPolarity, Resolution, SlaveAddr,
+    };
+    use embedded_hal_mock::delay::MockNoop;
+    use embedded_hal_mock::i2c::{Mock, Transaction};
+    use embedded_hal_mock::MockError;
+    use std::io::ErrorKind;
+
+    const DEV_ADDR: u8 = 0x40;
+
+    #[test]
+    fn sets_high_temperature_threshold() {
+        // -40.0 °C encodes to the minimum raw value, MSB 0x00.
+        let expectations = [Transaction::write(DEV_ADDR, vec![0x0B, 0x00])];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        dev.set_high_temperature_threshold(-40.0).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn sets_high_humidity_threshold() {
+        // 100.0 %RH encodes to the maximum raw value, MSB 0xFF.
+        let expectations = [Transaction::write(DEV_ADDR, vec![0x0D, 0xFF])];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        dev.set_high_humidity_threshold(100.0).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn sets_interrupt_mask() {
+        let mask = InterruptMask {
+            data_ready: true,
+            low_temperature: true,
+            ..Default::default()
+        };
+        let expectations = [Transaction::write(DEV_ADDR, vec![0x07, 0b1010_0000])];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        dev.set_interrupt_mask(mask).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn sets_interrupt_polarity_active_high() {
+        let expectations = [
+            Transaction::write_read(DEV_ADDR, vec![0x0E], vec![0b0000_0000]),
+            Transaction::write(DEV_ADDR, vec![0x0E, 0b0000_0010]),
+        ];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        dev.set_interrupt_polarity(InterruptPolarity::ActiveHigh)
+            .unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn enables_interrupt_pin_preserving_other_bits() {
+        let expectations = [
+            Transaction::write_read(DEV_ADDR, vec![0x0E], vec![0b0000_0010]),
+            Transaction::write(DEV_ADDR, vec![0x0E, 0b0000_0110]),
+        ];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        dev.enable_interrupt_pin().unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn read_only_checks_heater_status_once_despite_several_poll_iterations() {
+        let expectations = [
+            // Trigger the conversion.
+            Transaction::write_read(DEV_ADDR, vec![0x0F], vec![0b0000_0000]),
+            Transaction::write(DEV_ADDR, vec![0x0F, 0b0000_0010]),
+            // Two polls before data is ready; neither touches 0x0E.
+            Transaction::write_read(DEV_ADDR, vec![0x04], vec![0b0000_0000]),
+            Transaction::write_read(DEV_ADDR, vec![0x04], vec![0b1000_0000]),
+            // Only read_measurement() checks the heater bit, once.
+            Transaction::write_read(DEV_ADDR, vec![0x0E], vec![0b0000_1000]),
+            Transaction::write_read(DEV_ADDR, vec![0x00], vec![0x00]),
+            Transaction::write_read(DEV_ADDR, vec![0x01], vec![0x00]),
+            Transaction::write_read(DEV_ADDR, vec![0x02], vec![0x00]),
+            Transaction::write_read(DEV_ADDR, vec![0x03], vec![0x00]),
+        ];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        let measurement = dev.read().unwrap();
+        assert!(measurement.status.heater_active);
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn into_continuous_returns_original_instance_on_i2c_error() {
+        let expectations = [Transaction::write_read(DEV_ADDR, vec![0x0E], vec![0b0000_0000])
+            .with_error(MockError::Io(ErrorKind::Other))];
+        let dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        match dev.into_continuous(AutomaticMeasurementMode::OneHertz) {
+            Ok(_) => panic!("expected an error"),
+            Err((_, dev)) => dev.destroy().done(),
+        }
+    }
+
+    #[test]
+    fn reads_manufacturer_id() {
+        let expectations = [
+            Transaction::write_read(DEV_ADDR, vec![0xFC], vec![0x49]),
+            Transaction::write_read(DEV_ADDR, vec![0xFD], vec![0x54]),
+        ];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        assert_eq!(0x5449, dev.manufacturer_id().unwrap());
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn reads_device_id() {
+        let expectations = [
+            Transaction::write_read(DEV_ADDR, vec![0xFE], vec![0x07]),
+            Transaction::write_read(DEV_ADDR, vec![0xFF], vec![0x07]),
+        ];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        assert_eq!(0x0707, dev.device_id().unwrap());
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn is_hdc20xx_matches_ti_manufacturer_id() {
+        let expectations = [
+            Transaction::write_read(DEV_ADDR, vec![0xFC], vec![0x49]),
+            Transaction::write_read(DEV_ADDR, vec![0xFD], vec![0x54]),
+        ];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        assert!(dev.is_hdc20xx().unwrap());
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn is_hdc20xx_rejects_other_manufacturer_id() {
+        let expectations = [
+            Transaction::write_read(DEV_ADDR, vec![0xFC], vec![0x00]),
+            Transaction::write_read(DEV_ADDR, vec![0xFD], vec![0x00]),
+        ];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        assert!(!dev.is_hdc20xx().unwrap());
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn software_reset_sets_soft_res_bit_preserving_other_bits() {
+        let expectations = [
+            Transaction::write_read(DEV_ADDR, vec![0x0E], vec![0b0000_0010]),
+            Transaction::write(DEV_ADDR, vec![0x0E, 0b1000_0010]),
+        ];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        dev.software_reset(&mut MockNoop::new()).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn sets_temperature_offset() {
+        let expectations = [Transaction::write(DEV_ADDR, vec![0x08, 0x02])];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        dev.set_temperature_offset(1.0).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn sets_humidity_offset() {
+        let expectations = [Transaction::write(DEV_ADDR, vec![0x09, 0xF3])];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        dev.set_humidity_offset(-5.0).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn sets_temperature_resolution_preserving_humidity_resolution_bits() {
+        let expectations = [
+            Transaction::write_read(DEV_ADDR, vec![0x0F], vec![0b0011_0000]),
+            Transaction::write(DEV_ADDR, vec![0x0F, 0b0111_0000]),
+        ];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        dev.set_temperature_resolution(Resolution::Bits11).unwrap();
+        dev.destroy().done();
+    }
+
+    #[test]
+    fn sets_humidity_resolution_preserving_temperature_resolution_bits() {
+        let expectations = [
+            Transaction::write_read(DEV_ADDR, vec![0x0F], vec![0b1100_0000]),
+            Transaction::write(DEV_ADDR, vec![0x0F, 0b1110_0000]),
+        ];
+        let mut dev = Hdc20xx::new(Mock::new(&expectations), SlaveAddr::default());
+        dev.set_humidity_resolution(Resolution::Bits9).unwrap();
+        dev.destroy().done();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_humidity, encode_offset, encode_temperature, resolution_bits};
+    use crate::{Error, Resolution};
+
+    #[test]
+    fn encodes_resolution_bits() {
+        assert_eq!(0b00, resolution_bits(Resolution::Bits14));
+        assert_eq!(0b01, resolution_bits(Resolution::Bits11));
+        assert_eq!(0b10, resolution_bits(Resolution::Bits9));
+    }
+
+    #[test]
+    fn encodes_temperature_threshold() {
+        assert_eq!(0, encode_temperature::<()>(-40.0).unwrap());
+        assert_eq!(0xFF, encode_temperature::<()>(125.0).unwrap());
+    }
+
+    #[test]
+    fn rejects_out_of_range_temperature_threshold() {
+        match encode_temperature::<()>(-41.0) {
+            Err(Error::InvalidInputData) => (),
+            _ => panic!(),
+        }
+        match encode_temperature::<()>(126.0) {
+            Err(Error::InvalidInputData) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn encodes_humidity_threshold() {
+        assert_eq!(0, encode_humidity::<()>(0.0).unwrap());
+        assert_eq!(0xFF, encode_humidity::<()>(100.0).unwrap());
+    }
+
+    #[test]
+    fn rejects_out_of_range_humidity_threshold() {
+        match encode_humidity::<()>(-0.1) {
+            Err(Error::InvalidInputData) => (),
+            _ => panic!(),
+        }
+        match encode_humidity::<()>(100.1) {
+            Err(Error::InvalidInputData) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn encodes_offset_rounding_to_nearest_lsb() {
+        assert_eq!(0, encode_offset::<()>(0.0, 1.0).unwrap());
+        assert_eq!(2, encode_offset::<()>(1.6, 1.0).unwrap());
+        assert_eq!(0xFE, encode_offset::<()>(-1.6, 1.0).unwrap());
+    }
+
+    #[test]
+    fn rejects_offset_outside_signed_8_bit_range() {
+        match encode_offset::<()>(128.0, 1.0) {
+            Err(Error::InvalidInputData) => (),
+            _ => panic!(),
+        }
+        match encode_offset::<()>(-129.0, 1.0) {
+            Err(Error::InvalidInputData) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn rejects_non_finite_offset() {
+        match encode_offset::<()>(f32::NAN, 1.0) {
+            Err(Error::InvalidInputData) => (),
+            _ => panic!(),
+        }
+        match encode_offset::<()>(f32::INFINITY, 1.0) {
+            Err(Error::InvalidInputData) => (),
+            _ => panic!(),
+        }
+    }
+}
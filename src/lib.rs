@@ -0,0 +1,69 @@
+//! This is a platform agnostic Rust driver for the HDC20xx family of
+//! temperature and humidity sensors, based on the [`embedded-hal`] traits.
+//!
+//! [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+//!
+//! This driver allows you to:
+//! - Read the temperature and relative humidity.
+//!
+//! ## The device
+//!
+//! The HDC20xx is an integrated humidity and temperature sensor that
+//! provides high accuracy measurements with very low power consumption.
+//!
+//! ## Usage example
+//!
+//! ```no_run
+//! use hdc20xx::{Hdc20xx, SlaveAddr};
+//! use linux_embedded_hal::I2cdev;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let address = SlaveAddr::default();
+//! let mut sensor = Hdc20xx::new(dev, address);
+//! let measurement = sensor.read().unwrap();
+//! println!("Temperature: {}°C", measurement.temperature);
+//! ```
+
+#![deny(unsafe_code, missing_docs)]
+#![no_std]
+
+mod device_impl;
+pub mod mode;
+mod types;
+pub use crate::types::{
+    AutomaticMeasurementMode, Error, InterruptMask, InterruptPolarity, Measurement,
+    MeasurementMode, Resolution, SlaveAddr, Status,
+};
+
+/// Base slave address
+const BASE_ADDR: u8 = 0x40;
+
+/// HDC20xx device driver
+#[derive(Debug)]
+pub struct Hdc20xx<I2C, MODE> {
+    i2c: I2C,
+    address: u8,
+    _mode: core::marker::PhantomData<MODE>,
+}
+
+impl<I2C> Hdc20xx<I2C, mode::OneShot> {
+    /// Create new instance of the device.
+    ///
+    /// The device starts in one-shot mode. Use
+    /// [`into_continuous()`](Hdc20xx::into_continuous) to let the sensor
+    /// auto-convert on its own schedule instead.
+    pub fn new(i2c: I2C, address: SlaveAddr) -> Self {
+        Hdc20xx {
+            i2c,
+            address: address.addr(),
+            _mode: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<I2C, MODE> Hdc20xx<I2C, MODE> {
+    /// Destroy driver instance, return I2C bus instance.
+    pub fn destroy(self) -> I2C {
+        self.i2c
+    }
+}
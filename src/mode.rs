@@ -0,0 +1,20 @@
+//! Measurement mode typestates.
+//!
+//! These are used to select at compile time which API a [`Hdc20xx`](crate::Hdc20xx)
+//! instance exposes, depending on whether the device is driving its own
+//! conversions or needs to be triggered for every reading.
+
+/// One-shot measurement mode.
+///
+/// Every call to `read()` triggers a single conversion and waits for it to
+/// complete. This is the mode the device starts in.
+#[derive(Debug)]
+pub struct OneShot(());
+
+/// Continuous (automatic) measurement mode.
+///
+/// The device times its own conversions according to the configured
+/// [`AutomaticMeasurementMode`](crate::AutomaticMeasurementMode). `read()`
+/// simply returns the latest sample instead of triggering a new one.
+#[derive(Debug)]
+pub struct Continuous(());
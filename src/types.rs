@@ -35,6 +35,11 @@ pub struct Status {
     pub high_humidity_threshold_exceeded: bool,
     /// Whether the humidity low threshold was exceeded
     pub low_humidity_threshold_exceeded: bool,
+    /// Whether the on-chip heater is currently active.
+    ///
+    /// Readings taken while the heater is on are biased by its self-heating
+    /// and should be discarded.
+    pub heater_active: bool,
 }
 
 /// Measurement mode
@@ -78,6 +83,62 @@ impl SlaveAddr {
     }
 }
 
+/// INT/DRDY pin output polarity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterruptPolarity {
+    /// Active low (default)
+    ActiveLow,
+    /// Active high
+    ActiveHigh,
+}
+
+impl Default for InterruptPolarity {
+    /// Active low
+    fn default() -> Self {
+        InterruptPolarity::ActiveLow
+    }
+}
+
+/// Selects which status conditions are routed to the INT/DRDY pin.
+///
+/// Build one starting from `InterruptMask::default()` (nothing routed) and
+/// set the flags of interest, then pass it to
+/// [`Hdc20xx::set_interrupt_mask()`](crate::Hdc20xx::set_interrupt_mask).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct InterruptMask {
+    /// Route the data-ready condition to the pin
+    pub data_ready: bool,
+    /// Route the temperature high threshold exceeded condition to the pin
+    pub high_temperature: bool,
+    /// Route the temperature low threshold exceeded condition to the pin
+    pub low_temperature: bool,
+    /// Route the humidity high threshold exceeded condition to the pin
+    pub high_humidity: bool,
+    /// Route the humidity low threshold exceeded condition to the pin
+    pub low_humidity: bool,
+}
+
+/// ADC resolution for a temperature or humidity conversion.
+///
+/// Lower resolutions shorten the conversion time, which matters when paired
+/// with the higher [`AutomaticMeasurementMode`] rates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Resolution {
+    /// 14-bit resolution (default)
+    Bits14,
+    /// 11-bit resolution
+    Bits11,
+    /// 9-bit resolution
+    Bits9,
+}
+
+impl Default for Resolution {
+    /// 14-bit resolution
+    fn default() -> Self {
+        Resolution::Bits14
+    }
+}
+
 /// Possible automatic measurement mode choices.
 #[repr(u8)]
 #[derive(Copy, Debug, PartialEq, Eq, Clone)]